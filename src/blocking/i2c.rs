@@ -1,15 +1,202 @@
 //! Blocking I2C API
 //!
-//! Slave addresses used by this API are 7-bit I2C addresses ranging from 0 to 127.
-//!
-//! Operations on 10-bit slave addresses are not supported by the API yet (but applications might
-//! be able to emulate some operations).
+//! This API supports both 7-bit and 10-bit addresses through the [`Address`]
+//! type. An `Address` is constructed with [`Address::new`] (7-bit) or
+//! [`Address::new_10bit`] (10-bit); the former rejects the 7-bit ranges reserved
+//! by the I2C specification and values above `0x7F`, the latter values above
+//! `0x3FF`. [`Address::is_ten_bit`] lets a HAL decide whether to
+//! emit 7-bit or 10-bit framing. HALs whose hardware lacks native 10-bit support
+//! can still software-emulate it, since the 10-bit protocol is backwards
+//! compatible (the first byte carries `11110` plus the top two address bits).
 
-/// Blocking read
-pub trait Read {
+use core::fmt::{self, Debug};
+
+/// I2C error
+pub trait Error: Debug {
+    /// Convert error to a generic I2C error kind
+    ///
+    /// By using this method, I2C errors freely defined by HAL implementations
+    /// can be converted to a set of generic I2C errors upon which generic
+    /// code can act.
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Error for core::convert::Infallible {
+    fn kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+/// I2C error kind
+///
+/// This represents a common set of I2C operation errors. HAL implementations are
+/// free to define more specific or additional error types. However, by providing
+/// a mapping to these common I2C errors, generic code can still react to them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Bus error occurred. e.g. A START or a STOP condition is detected and is not
+    /// located after a multiple of 9 SCL clock pulses.
+    Bus,
+    /// The arbitration was lost, e.g. electrical problems with the clock signal
+    ArbitrationLoss,
+    /// A bus operation was not acknowledged, e.g. due to the addressed device not
+    /// being available on the bus or the device not being ready to process requests
+    /// at the moment
+    NoAcknowledge(NoAcknowledgeSource),
+    /// The peripheral receive buffer was overrun
+    Overrun,
+    /// The target address was rejected before reaching the bus, e.g. a reserved
+    /// address or a value outside the range permitted by the addressing mode.
+    Address(AddressError),
+}
+
+/// I2C no acknowledge error source
+///
+/// In cases where it is possible, a device should indicate if a no acknowledge
+/// response was received to an address versus a no acknowledge to a data byte.
+/// Where it is not possible to differentiate, `Unknown` should be indicated.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum NoAcknowledgeSource {
+    /// The device did not acknowledge its address. The device may be missing.
+    Address,
+    /// The device did not acknowledge the data. It may not be ready to process
+    /// requests at the moment.
+    Data,
+    /// Either the device did not acknowledge its address or the data, but it is
+    /// unknown which.
+    Unknown,
+}
+
+impl fmt::Display for NoAcknowledgeSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address => write!(f, "The device did not acknowledge its address"),
+            Self::Data => write!(f, "The device did not acknowledge the data"),
+            Self::Unknown => write!(f, "The device did not acknowledge its address or the data"),
+        }
+    }
+}
+
+impl Error for ErrorKind {
+    fn kind(&self) -> ErrorKind {
+        *self
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bus => write!(f, "Bus error occurred"),
+            Self::ArbitrationLoss => write!(f, "The arbitration was lost"),
+            Self::NoAcknowledge(s) => s.fmt(f),
+            Self::Overrun => write!(f, "The receive buffer was overrun"),
+            Self::Address(e) => e.fmt(f),
+        }
+    }
+}
+
+/// Reason an [`Address`] could not be constructed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum AddressError {
+    /// The address falls into a range reserved by the I2C specification.
+    ///
+    /// The ranges `0x00..=0x07` (general call, CBUS, ...) and `0x78..=0x7F`
+    /// (10-bit addressing prefixes, device ID, ...) are reserved for 7-bit
+    /// addresses.
+    AddressReserved,
+    /// The address is larger than the addressing mode allows, e.g. above `0x7F`
+    /// for a 7-bit address or above `0x3FF` for a 10-bit address.
+    AddressOutOfRange,
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddressReserved => write!(f, "The address is reserved by the I2C specification"),
+            Self::AddressOutOfRange => write!(f, "The address is out of range for the addressing mode"),
+        }
+    }
+}
+
+/// A validated I2C slave address.
+///
+/// Every trait method takes an `Address` by value, so a caller must go through
+/// [`Address::new`] or [`Address::new_10bit`] — which reject reserved and
+/// out-of-range addresses — before an address can reach the bus. This
+/// gives HALs a single place to turn a bad address into an [`AddressError`]
+/// instead of undefined bus behavior. Callers that deliberately target a reserved
+/// address (general call, CBUS) opt out with [`Address::new_unchecked`].
+///
+/// The address also records whether it is a 7-bit or 10-bit address so a HAL can
+/// pick the correct framing via [`Address::is_ten_bit`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Address {
+    value: u16,
+    ten_bit: bool,
+}
+
+impl Address {
+    /// Creates a 7-bit address, rejecting the reserved ranges `0x00..=0x07` and
+    /// `0x78..=0x7F` and values above `0x7F`.
+    pub fn new(address: u8) -> Result<Self, AddressError> {
+        match address {
+            0x00..=0x07 | 0x78..=0x7F => Err(AddressError::AddressReserved),
+            0x80..=0xFF => Err(AddressError::AddressOutOfRange),
+            _ => Ok(Address { value: u16::from(address), ten_bit: false }),
+        }
+    }
+
+    /// Creates a 10-bit address, rejecting values above `0x3FF`.
+    pub fn new_10bit(address: u16) -> Result<Self, AddressError> {
+        if address > 0x3FF {
+            Err(AddressError::AddressOutOfRange)
+        } else {
+            Ok(Address { value: address, ten_bit: true })
+        }
+    }
+
+    /// Creates a 7-bit address without validating it.
+    ///
+    /// This is intended for callers that deliberately target a reserved address,
+    /// such as the general call address or CBUS devices.
+    pub fn new_unchecked(address: u8) -> Self {
+        Address { value: u16::from(address), ten_bit: false }
+    }
+
+    /// Returns the raw address value.
+    pub fn value(self) -> u16 {
+        self.value
+    }
+
+    /// Returns `true` if this is a 10-bit address.
+    pub fn is_ten_bit(self) -> bool {
+        self.ten_bit
+    }
+}
+
+impl From<AddressError> for ErrorKind {
+    fn from(error: AddressError) -> Self {
+        ErrorKind::Address(error)
+    }
+}
+
+/// I2C error type trait
+///
+/// This just defines the error type, to be used by the other traits.
+pub trait ErrorType {
     /// Error type
-    type Error;
+    type Error: Error;
+}
 
+impl<T: ErrorType> ErrorType for &mut T {
+    type Error = T::Error;
+}
+
+/// Blocking read
+pub trait Read: ErrorType {
     /// Reads enough bytes from slave with `address` to fill `buffer`
     ///
     /// # I2C Events (contract)
@@ -28,22 +215,17 @@ pub trait Read {
     /// - `MAK` = master acknowledge
     /// - `NMAK` = master no acknowledge
     /// - `SP` = stop condition
-    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error>;
+    fn read(&mut self, address: Address, buffer: &mut [u8]) -> Result<(), Self::Error>;
 }
 
 impl<T: Read> Read for &mut T {
-    type Error = <T as Read>::Error;
-
-    fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+    fn read(&mut self, address: Address, buffer: &mut [u8]) -> Result<(), Self::Error> {
         (*self).read(address, buffer)
     }
 }
 
 /// Blocking write
-pub trait Write {
-    /// Error type
-    type Error;
-
+pub trait Write: ErrorType {
     /// Sends bytes to slave with address `addr`
     ///
     /// # I2C Events (contract)
@@ -60,38 +242,31 @@ pub trait Write {
     /// - `SAK` = slave acknowledge
     /// - `Bi` = ith byte of data
     /// - `SP` = stop condition
-    fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error>;
+    fn write(&mut self, addr: Address, bytes: &[u8]) -> Result<(), Self::Error>;
 }
 
 impl<T: Write> Write for &mut T {
-    type Error = <T as Write>::Error;
-
-    fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+    fn write(&mut self, addr: Address, bytes: &[u8]) -> Result<(), Self::Error> {
         (*self).write(addr, bytes)
     }
 }
 
 /// Blocking write (iterator version)
 #[cfg(feature = "unproven")]
-pub trait WriteIter {
-    /// Error type
-    type Error;
-
+pub trait WriteIter: ErrorType {
     /// Sends bytes to slave with address `addr`
     ///
     /// # I2C Events (contract)
     ///
     /// Same as `Write`
-    fn write<B>(&mut self, addr: u8, bytes: B) -> Result<(), Self::Error>
+    fn write<B>(&mut self, addr: Address, bytes: B) -> Result<(), Self::Error>
     where
         B: IntoIterator<Item = u8>;
 }
 
 #[cfg(feature = "unproven")]
 impl<T: WriteIter> WriteIter for &mut T {
-    type Error = <T as WriteIter>::Error;
-
-    fn write<B>(&mut self, addr: u8, bytes: B) -> Result<(), Self::Error>
+    fn write<B>(&mut self, addr: Address, bytes: B) -> Result<(), Self::Error>
     where
         B: IntoIterator<Item = u8> {
         (*self).write(addr, bytes)
@@ -99,10 +274,7 @@ impl<T: WriteIter> WriteIter for &mut T {
 }
 
 /// Blocking write + read
-pub trait WriteRead {
-    /// Error type
-    type Error;
-
+pub trait WriteRead: ErrorType {
     /// Sends bytes to slave with address `addr` and then reads enough bytes to fill `buffer` *in a
     /// single transaction*
     ///
@@ -127,26 +299,21 @@ pub trait WriteRead {
     /// - `SP` = stop condition
     fn write_read(
         &mut self,
-        address: u8,
+        address: Address,
         bytes: &[u8],
         buffer: &mut [u8],
     ) -> Result<(), Self::Error>;
 }
 
 impl<T: WriteRead> WriteRead for &mut T {
-    type Error = <T as WriteRead>::Error;
-
-    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+    fn write_read(&mut self, address: Address, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
         (*self).write_read(address, bytes, buffer)
     }
 }
 
 /// Blocking write (iterator version) + read
 #[cfg(feature = "unproven")]
-pub trait WriteIterRead {
-    /// Error type
-    type Error;
-
+pub trait WriteIterRead: ErrorType {
     /// Sends bytes to slave with address `addr` and then reads enough bytes to fill `buffer` *in a
     /// single transaction*
     ///
@@ -155,7 +322,7 @@ pub trait WriteIterRead {
     /// Same as the `WriteRead` trait
     fn write_iter_read<B>(
         &mut self,
-        address: u8,
+        address: Address,
         bytes: B,
         buffer: &mut [u8],
     ) -> Result<(), Self::Error>
@@ -163,13 +330,114 @@ pub trait WriteIterRead {
         B: IntoIterator<Item = u8>;
 }
 
-#[cfg(unproven)]
+#[cfg(feature = "unproven")]
 impl<T: WriteIterRead> WriteIterRead for &mut T {
-    type Error = <T as WriteIterRead>::Error;
-
-    fn write_iter_read<B>(&mut self, address: u8, bytes: B, buffer: &mut [u8]) -> Result<(), Self::Error>
+    fn write_iter_read<B>(&mut self, address: Address, bytes: B, buffer: &mut [u8]) -> Result<(), Self::Error>
     where
         B: IntoIterator<Item = u8> {
         (*self).write_iter_read(address, bytes, buffer)
     }
 }
+
+/// Transactional I2C operation.
+///
+/// Several operations can be combined as part of a transaction.
+#[derive(Debug, PartialEq)]
+pub enum Operation<'a> {
+    /// Read data into the provided buffer
+    Read(&'a mut [u8]),
+    /// Write data from the provided buffer
+    Write(&'a [u8]),
+}
+
+/// Transactional I2C interface.
+///
+/// This allows combining operations within an I2C transaction.
+pub trait Transactional: ErrorType {
+    /// Execute the provided operations on the I2C bus.
+    ///
+    /// Transaction contract:
+    /// - Before executing the first operation an ST is sent automatically. This is followed by
+    ///   SAD+R/W as appropriate.
+    /// - Data from adjacent operations of the same type are sent after each other without an SP or SR.
+    /// - Between adjacent operations of a different type an SR and SAD+R/W is sent.
+    /// - After executing the last operation an SP is sent automatically.
+    /// - If the last operation is a `Read` the master does not send an acknowledge for the last byte.
+    ///
+    /// ``` text
+    /// - `ST` = start condition
+    /// - `SAD+W` = slave address followed by bit 0 to indicate writing
+    /// - `SAK` = slave acknowledge
+    /// - `Oi` = ith outgoing byte of data
+    /// - `SR` = repeated start condition
+    /// - `SAD+R` = slave address followed by bit 1 to indicate reading
+    /// - `Ii` = ith incoming byte of data
+    /// - `MAK` = master acknowledge
+    /// - `NMAK` = master no acknowledge
+    /// - `SP` = stop condition
+    /// ```
+    fn exec(&mut self, address: Address, operations: &mut [Operation<'_>]) -> Result<(), Self::Error>;
+}
+
+impl<T: Transactional> Transactional for &mut T {
+    fn exec(&mut self, address: Address, operations: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        (*self).exec(address, operations)
+    }
+}
+
+/// Transactional I2C interface (iterator version).
+///
+/// This allows combining operations within an I2C transaction.
+#[cfg(feature = "unproven")]
+pub trait TransactionalIter: ErrorType {
+    /// Execute the provided operations on the I2C bus (iterator version).
+    ///
+    /// Transaction contract is the same as for [`Transactional::exec`].
+    ///
+    /// [`Transactional::exec`]: trait.Transactional.html#tymethod.exec
+    fn exec_iter<'a, O>(&mut self, address: Address, operations: O) -> Result<(), Self::Error>
+    where
+        O: IntoIterator<Item = Operation<'a>>;
+}
+
+#[cfg(feature = "unproven")]
+impl<T: TransactionalIter> TransactionalIter for &mut T {
+    fn exec_iter<'a, O>(&mut self, address: Address, operations: O) -> Result<(), Self::Error>
+    where
+        O: IntoIterator<Item = Operation<'a>> {
+        (*self).exec_iter(address, operations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Address, AddressError};
+
+    #[test]
+    fn seven_bit_rejects_reserved() {
+        assert_eq!(Address::new(0x00), Err(AddressError::AddressReserved));
+        assert_eq!(Address::new(0x07), Err(AddressError::AddressReserved));
+        assert_eq!(Address::new(0x78), Err(AddressError::AddressReserved));
+        assert_eq!(Address::new(0x7F), Err(AddressError::AddressReserved));
+    }
+
+    #[test]
+    fn seven_bit_accepts_valid() {
+        assert_eq!(Address::new(0x08).map(Address::value), Ok(0x08));
+        assert_eq!(Address::new(0x77).map(Address::value), Ok(0x77));
+        assert_eq!(Address::new(0x50).map(Address::is_ten_bit), Ok(false));
+    }
+
+    #[test]
+    fn ten_bit_rejects_out_of_range() {
+        assert_eq!(Address::new_10bit(0x400), Err(AddressError::AddressOutOfRange));
+        assert_eq!(Address::new_10bit(0x3FF).map(Address::value), Ok(0x3FF));
+        assert_eq!(Address::new_10bit(0x3FF).map(Address::is_ten_bit), Ok(true));
+    }
+
+    // The `Transactional`/`TransactionalIter` sequencing contract and the async
+    // traits are not exercised here: observing the emitted START/repeated-START/
+    // STOP conditions requires a mock I2C peripheral, which is out of scope for
+    // this crate (it ships only the trait definitions). Conformance for those is
+    // covered in the HAL implementations.
+}
@@ -0,0 +1,127 @@
+//! Async I2C API
+//!
+//! This is the async counterpart of the [blocking I2C API](crate::blocking::i2c).
+//! The traits mirror the blocking `Read`, `Write`, `WriteRead`, `WriteIter` and
+//! `WriteIterRead` traits but their methods return futures (there is no async
+//! `Transactional` or `TransactionalIter`), allowing a HAL to yield while a DMA transfer or FIFO drains instead
+//! of busy-waiting. The event contracts and the `Address`/error abstractions
+//! are shared with the blocking module, so a device driver written against a
+//! generic bound can be compiled for either a blocking or an async executor.
+//!
+//! # `Send` futures
+//!
+//! The trait methods use `async fn`, so the returned future captures `&mut Self`
+//! and is not bound to be [`Send`]. Executors that move tasks between threads
+//! require `Send` futures; implementors targeting such executors should make sure
+//! their futures are `Send` and callers can add an explicit bound where needed.
+
+// `async fn` in a public trait desugars to an unnameable RPITIT future. The lint
+// is acceptable here for the same reasons as in `embedded-hal-async`: see the
+// `Send` futures note above.
+#![allow(async_fn_in_trait)]
+
+use crate::blocking::i2c::{Address, ErrorType};
+
+/// Async read
+pub trait Read: ErrorType {
+    /// Reads enough bytes from slave with `address` to fill `buffer`
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// Same as the blocking [`Read`](crate::blocking::i2c::Read) trait.
+    async fn read(&mut self, address: Address, buffer: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+impl<T: Read> Read for &mut T {
+    async fn read(&mut self, address: Address, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        (*self).read(address, buffer).await
+    }
+}
+
+/// Async write
+pub trait Write: ErrorType {
+    /// Sends bytes to slave with address `addr`
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// Same as the blocking [`Write`](crate::blocking::i2c::Write) trait.
+    async fn write(&mut self, addr: Address, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+
+impl<T: Write> Write for &mut T {
+    async fn write(&mut self, addr: Address, bytes: &[u8]) -> Result<(), Self::Error> {
+        (*self).write(addr, bytes).await
+    }
+}
+
+/// Async write (iterator version)
+#[cfg(feature = "unproven")]
+pub trait WriteIter: ErrorType {
+    /// Sends bytes to slave with address `addr`
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// Same as `Write`
+    async fn write<B>(&mut self, addr: Address, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>;
+}
+
+#[cfg(feature = "unproven")]
+impl<T: WriteIter> WriteIter for &mut T {
+    async fn write<B>(&mut self, addr: Address, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8> {
+        (*self).write(addr, bytes).await
+    }
+}
+
+/// Async write + read
+pub trait WriteRead: ErrorType {
+    /// Sends bytes to slave with address `addr` and then reads enough bytes to fill `buffer` *in a
+    /// single transaction*
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// Same as the blocking [`WriteRead`](crate::blocking::i2c::WriteRead) trait.
+    async fn write_read(
+        &mut self,
+        address: Address,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>;
+}
+
+impl<T: WriteRead> WriteRead for &mut T {
+    async fn write_read(&mut self, address: Address, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        (*self).write_read(address, bytes, buffer).await
+    }
+}
+
+/// Async write (iterator version) + read
+#[cfg(feature = "unproven")]
+pub trait WriteIterRead: ErrorType {
+    /// Sends bytes to slave with address `addr` and then reads enough bytes to fill `buffer` *in a
+    /// single transaction*
+    ///
+    /// # I2C Events (contract)
+    ///
+    /// Same as the `WriteRead` trait
+    async fn write_iter_read<B>(
+        &mut self,
+        address: Address,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+        where
+        B: IntoIterator<Item = u8>;
+}
+
+#[cfg(feature = "unproven")]
+impl<T: WriteIterRead> WriteIterRead for &mut T {
+    async fn write_iter_read<B>(&mut self, address: Address, bytes: B, buffer: &mut [u8]) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8> {
+        (*self).write_iter_read(address, bytes, buffer).await
+    }
+}